@@ -20,6 +20,10 @@ where
 {
     /// Creates a new line segment.
     ///
+    /// Accepts anything that converts into a [`Coordinate`](struct.Coordinate.html),
+    /// matching how [`LineString::from`](struct.LineString.html) coerces its
+    /// elements.
+    ///
     /// # Examples
     ///
     /// ```
@@ -33,10 +37,19 @@ where
     /// assert_eq!(line.start, Coordinate { x: 0., y: 0. });
     /// assert_eq!(line.end, Coordinate { x: 1., y: 2. });
     /// ```
-    pub fn new(start: Coordinate<T>, end: Coordinate<T>) -> Line<T> {
+    ///
+    /// ```
+    /// use geo_types::Line;
+    ///
+    /// let line = Line::new((0., 0.), (1., 2.));
+    /// ```
+    pub fn new<C>(start: C, end: C) -> Line<T>
+    where
+        C: Into<Coordinate<T>>,
+    {
         Line {
-            start,
-            end
+            start: start.into(),
+            end: end.into(),
         }
     }
 
@@ -78,6 +91,28 @@ where
         self.end.y - self.start.y
     }
 
+    /// Calculate the vector between `start` and `end`, i.e. `(dx, dy)`.
+    ///
+    /// Equivalent to:
+    ///
+    /// ```rust
+    /// # use geo_types::{Line, Coordinate, Point};
+    /// # let line = Line::new(
+    /// #     Point(Coordinate { x: 4., y: -12. }),
+    /// #     Point(Coordinate { x: 0., y: 9. }),
+    /// # );
+    /// # assert_eq!(
+    /// #     line.delta(),
+    /// Coordinate { x: line.dx(), y: line.dy() }
+    /// # );
+    /// ```
+    pub fn delta(&self) -> Coordinate<T> {
+        Coordinate {
+            x: self.dx(),
+            y: self.dy(),
+        }
+    }
+
     /// Calculate the slope (Δy/Δx).
     ///
     /// Equivalent to:
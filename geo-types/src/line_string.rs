@@ -1,5 +1,6 @@
 use std::iter::FromIterator;
 use {Coordinate, CoordinateType, Line};
+use lines_iter::LinesIter;
 
 /// An ordered collection of two or more [`Coordinate`s](struct.Coordinate.html), representing a
 /// path between locations.
@@ -95,11 +96,48 @@ impl<T: CoordinateType> LineString<T> {
     /// assert!(lines.next().is_none());
     /// ```
     pub fn lines<'a>(&'a self) -> impl Iterator<Item = Line<T>> + 'a {
-        self.0.windows(2).map(|w| unsafe {
-            // As long as the LineString has at least two coordinates, we shouldn't
-            // need to do bounds checking here.
-            Line::new(*w.get_unchecked(0), *w.get_unchecked(1))
-        })
+        self.lines_iter()
+    }
+
+    /// Returns `true` if the linestring is closed, per the OGC-SFA
+    /// definition: empty, or its first and last coordinates coincide.
+    ///
+    /// A closed `LineString` is also known as a linear ring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::LineString;
+    ///
+    /// let open: LineString<f32> = vec![(0., 0.), (5., 0.), (5., 5.)].into();
+    /// assert!(!open.is_closed());
+    ///
+    /// let closed: LineString<f32> = vec![(0., 0.), (5., 0.), (5., 5.), (0., 0.)].into();
+    /// assert!(closed.is_closed());
+    /// ```
+    pub fn is_closed(&self) -> bool {
+        self.0.first() == self.0.last()
+    }
+
+    /// Close the linestring, if it isn't already, by appending a copy of the
+    /// first coordinate to the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::LineString;
+    ///
+    /// let mut line_string: LineString<f32> = vec![(0., 0.), (5., 0.), (5., 5.)].into();
+    /// line_string.close();
+    /// assert!(line_string.is_closed());
+    /// assert_eq!(line_string.0.last(), line_string.0.first());
+    /// ```
+    pub fn close(&mut self) {
+        if !self.is_closed() {
+            if let Some(first) = self.0.first().cloned() {
+                self.0.push(first);
+            }
+        }
     }
 }
 
@@ -126,3 +164,47 @@ impl<T: CoordinateType> IntoIterator for LineString<T> {
         self.0.into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_line_string_is_closed() {
+        let line_string: LineString<f64> = vec![].into();
+        assert!(line_string.is_closed());
+    }
+
+    #[test]
+    fn open_line_string_is_not_closed() {
+        let line_string: LineString<f64> = vec![(0., 0.), (5., 0.), (5., 5.)].into();
+        assert!(!line_string.is_closed());
+    }
+
+    #[test]
+    fn coincident_first_last_is_closed() {
+        let line_string: LineString<f64> = vec![(0., 0.), (5., 0.), (5., 5.), (0., 0.)].into();
+        assert!(line_string.is_closed());
+    }
+
+    #[test]
+    fn close_appends_first_coordinate() {
+        let mut line_string: LineString<f64> = vec![(0., 0.), (5., 0.), (5., 5.)].into();
+        line_string.close();
+        assert_eq!(line_string.0, vec![
+            Coordinate { x: 0., y: 0. },
+            Coordinate { x: 5., y: 0. },
+            Coordinate { x: 5., y: 5. },
+            Coordinate { x: 0., y: 0. },
+        ]);
+    }
+
+    #[test]
+    fn close_is_a_no_op_if_already_closed() {
+        let mut line_string: LineString<f64> =
+            vec![(0., 0.), (5., 0.), (5., 5.), (0., 0.)].into();
+        let before = line_string.clone();
+        line_string.close();
+        assert_eq!(line_string, before);
+    }
+}
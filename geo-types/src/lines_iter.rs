@@ -0,0 +1,117 @@
+use std::iter;
+use std::slice;
+
+use {Coordinate, CoordinateType, Line, LineString, MultiLineString};
+
+/// Iterate over the [`Line`](struct.Line.html) segments of a geometry, in
+/// order.
+///
+/// This generalizes [`LineString::lines`](struct.LineString.html#method.lines)
+/// to `Line` (which yields itself once), `LineString` and `MultiLineString`,
+/// so algorithms that only care about "the segments of this thing" (length,
+/// intersection, simplification, ...) don't need to special-case each of
+/// these types. Polygonal geometries such as `Polygon` and `Rect` do not
+/// implement it.
+pub trait LinesIter<'a> {
+    type Scalar: CoordinateType;
+    type Iter: Iterator<Item = Line<Self::Scalar>>;
+
+    /// Iterate over all line segments that make up this geometry.
+    fn lines_iter(&'a self) -> Self::Iter;
+}
+
+impl<'a, T: CoordinateType + 'a> LinesIter<'a> for Line<T> {
+    type Scalar = T;
+    type Iter = iter::Once<Line<T>>;
+
+    fn lines_iter(&'a self) -> Self::Iter {
+        iter::once(*self)
+    }
+}
+
+impl<'a, T: CoordinateType + 'a> LinesIter<'a> for LineString<T> {
+    type Scalar = T;
+    type Iter = LineStringLinesIter<'a, T>;
+
+    fn lines_iter(&'a self) -> Self::Iter {
+        LineStringLinesIter(self.0.windows(2))
+    }
+}
+
+/// Iterator over the [`Line`s](struct.Line.html) of a [`LineString`](struct.LineString.html).
+///
+/// Created by [`LineString::lines_iter`](struct.LineString.html#method.lines_iter).
+#[derive(Debug)]
+pub struct LineStringLinesIter<'a, T: CoordinateType>(slice::Windows<'a, Coordinate<T>>);
+
+impl<'a, T: CoordinateType> Iterator for LineStringLinesIter<'a, T> {
+    type Item = Line<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|w| unsafe {
+            // A window of size 2 always has both elements in bounds.
+            Line::new(*w.get_unchecked(0), *w.get_unchecked(1))
+        })
+    }
+}
+
+impl<'a, T: CoordinateType + 'a> LinesIter<'a> for MultiLineString<T> {
+    type Scalar = T;
+    type Iter = iter::FlatMap<
+        slice::Iter<'a, LineString<T>>,
+        LineStringLinesIter<'a, T>,
+        fn(&'a LineString<T>) -> LineStringLinesIter<'a, T>,
+    >;
+
+    fn lines_iter(&'a self) -> Self::Iter {
+        fn lines_iter_of<T: CoordinateType>(
+            line_string: &LineString<T>,
+        ) -> LineStringLinesIter<T> {
+            line_string.lines_iter()
+        }
+
+        self.0.iter().flat_map(lines_iter_of)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_yields_itself_once() {
+        let line = Line::new((0., 0.), (1., 1.));
+        let lines: Vec<_> = line.lines_iter().collect();
+        assert_eq!(lines, vec![line]);
+    }
+
+    #[test]
+    fn line_string_yields_consecutive_segments() {
+        let line_string: LineString<f64> = vec![(0., 0.), (1., 0.), (1., 1.)].into();
+        let lines: Vec<_> = line_string.lines_iter().collect();
+        assert_eq!(
+            lines,
+            vec![
+                Line::new((0., 0.), (1., 0.)),
+                Line::new((1., 0.), (1., 1.)),
+            ]
+        );
+    }
+
+    #[test]
+    fn multi_line_string_flattens_members() {
+        let a: LineString<f64> = vec![(0., 0.), (1., 0.)].into();
+        let b: LineString<f64> = vec![(5., 5.), (6., 5.), (6., 6.)].into();
+        let multi = MultiLineString(vec![a, b]);
+
+        let lines: Vec<_> = multi.lines_iter().collect();
+        assert_eq!(
+            lines,
+            vec![
+                Line::new((0., 0.), (1., 0.)),
+                Line::new((5., 5.), (6., 5.)),
+                Line::new((6., 5.), (6., 6.)),
+            ]
+        );
+    }
+}
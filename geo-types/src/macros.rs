@@ -0,0 +1,42 @@
+/// Construct a [`LineString`](struct.LineString.html) or
+/// [`MultiLineString`](struct.MultiLineString.html) from a [WKT][wkt] literal,
+/// expanded at compile time — analogous to `line_string!`, but for the
+/// standard WKT textual forms.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::wkt;
+///
+/// let line_string = wkt!(LINESTRING(0. 0., 10. 0.));
+/// assert_eq!(line_string.0.len(), 2);
+///
+/// let multi_line_string = wkt!(MULTILINESTRING((0. 0., 1. 1.), (2. 2., 3. 3.)));
+/// assert_eq!(multi_line_string.0.len(), 2);
+///
+/// // Each point is written as two whitespace-separated literals, points
+/// // within a linestring are comma-separated.
+/// let _ = wkt!(LINESTRING(0. 0., 5. 5., 10. 0.));
+/// ```
+///
+/// [wkt]: https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry
+#[macro_export]
+macro_rules! wkt {
+    (LINESTRING EMPTY) => {
+        $crate::LineString(::std::vec::Vec::new())
+    };
+    (LINESTRING ( $($x:literal $y:literal),+ $(,)? )) => {
+        $crate::LineString(vec![
+            $($crate::Coordinate { x: $x, y: $y }),+
+        ])
+    };
+    (MULTILINESTRING ( $( ( $($x:literal $y:literal),+ ) ),+ $(,)? )) => {
+        $crate::MultiLineString(vec![
+            $(
+                $crate::LineString(vec![
+                    $($crate::Coordinate { x: $x, y: $y }),+
+                ])
+            ),+
+        ])
+    };
+}
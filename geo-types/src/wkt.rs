@@ -0,0 +1,263 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use {Coordinate, CoordinateType, Line, LineString, MultiLineString};
+
+/// An error encountered while parsing a [Well-Known Text][wkt] string.
+///
+/// [wkt]: https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseWktError(String);
+
+impl fmt::Display for ParseWktError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to parse WKT: {}", self.0)
+    }
+}
+
+impl Error for ParseWktError {
+    fn description(&self) -> &str {
+        "failed to parse WKT"
+    }
+}
+
+/// Serialize a geometry as a [Well-Known Text][wkt] string.
+///
+/// [wkt]: https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry
+pub trait ToWkt {
+    fn to_wkt(&self) -> String;
+}
+
+fn fmt_coord<T: fmt::Display>(coord: &Coordinate<T>) -> String {
+    format!("{} {}", coord.x, coord.y)
+}
+
+fn fmt_coords<T: fmt::Display>(coords: &[Coordinate<T>]) -> String {
+    coords
+        .iter()
+        .map(fmt_coord)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl<T: fmt::Display + CoordinateType> fmt::Display for Line<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LINESTRING ({})", fmt_coords(&[self.start, self.end]))
+    }
+}
+
+impl<T: fmt::Display + CoordinateType> fmt::Display for LineString<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LINESTRING ({})", fmt_coords(&self.0))
+    }
+}
+
+impl<T: fmt::Display + CoordinateType> fmt::Display for MultiLineString<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let line_strings = self
+            .0
+            .iter()
+            .map(|ls| format!("({})", fmt_coords(&ls.0)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "MULTILINESTRING ({})", line_strings)
+    }
+}
+
+impl<T: fmt::Display + CoordinateType> ToWkt for Line<T> {
+    fn to_wkt(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<T: fmt::Display + CoordinateType> ToWkt for LineString<T> {
+    fn to_wkt(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<T: fmt::Display + CoordinateType> ToWkt for MultiLineString<T> {
+    fn to_wkt(&self) -> String {
+        self.to_string()
+    }
+}
+
+fn strip_tag<'a>(input: &'a str, tag: &str) -> Result<&'a str, ParseWktError> {
+    let input = input.trim();
+    match input.get(..tag.len()) {
+        Some(head) if head.eq_ignore_ascii_case(tag) => Ok(input[tag.len()..].trim()),
+        _ => Err(ParseWktError(format!("expected a {} tag", tag))),
+    }
+}
+
+fn strip_parens(input: &str) -> Result<&str, ParseWktError> {
+    let input = input.trim();
+    if !input.starts_with('(') || !input.ends_with(')') {
+        return Err(ParseWktError(
+            "expected a parenthesized coordinate list".into(),
+        ));
+    }
+    Ok(&input[1..input.len() - 1])
+}
+
+/// Split a string on top-level commas only, ignoring commas nested inside
+/// parentheses (used to split the member linestrings of a MULTILINESTRING).
+fn split_top_level(input: &str) -> Vec<&str> {
+    let mut groups = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                groups.push(input[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    let last = input[start..].trim();
+    if !last.is_empty() {
+        groups.push(last);
+    }
+    groups
+}
+
+fn parse_coord<T>(input: &str) -> Result<Coordinate<T>, ParseWktError>
+where
+    T: CoordinateType + FromStr,
+{
+    let mut parts = input.split_whitespace();
+    let x = parts
+        .next()
+        .ok_or_else(|| ParseWktError("expected an x coordinate".into()))?;
+    let y = parts
+        .next()
+        .ok_or_else(|| ParseWktError("expected a y coordinate".into()))?;
+    let x = x
+        .parse()
+        .map_err(|_| ParseWktError(format!("invalid number: {}", x)))?;
+    let y = y
+        .parse()
+        .map_err(|_| ParseWktError(format!("invalid number: {}", y)))?;
+    Ok(Coordinate { x, y })
+}
+
+fn parse_coord_list<T>(input: &str) -> Result<Vec<Coordinate<T>>, ParseWktError>
+where
+    T: CoordinateType + FromStr,
+{
+    split_top_level(strip_parens(input)?)
+        .into_iter()
+        .map(parse_coord)
+        .collect()
+}
+
+impl<T> FromStr for Line<T>
+where
+    T: CoordinateType + FromStr,
+{
+    type Err = ParseWktError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coords = parse_coord_list(strip_tag(s, "LINESTRING")?)?;
+        match coords.len() {
+            2 => Ok(Line::new(coords[0], coords[1])),
+            n => Err(ParseWktError(format!(
+                "a `Line` needs exactly 2 points, found {}",
+                n
+            ))),
+        }
+    }
+}
+
+impl<T> FromStr for LineString<T>
+where
+    T: CoordinateType + FromStr,
+{
+    type Err = ParseWktError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(LineString(parse_coord_list(strip_tag(s, "LINESTRING")?)?))
+    }
+}
+
+impl<T> FromStr for MultiLineString<T>
+where
+    T: CoordinateType + FromStr,
+{
+    type Err = ParseWktError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = strip_tag(s, "MULTILINESTRING")?;
+        let line_strings = split_top_level(strip_parens(rest)?)
+            .into_iter()
+            .map(|group| Ok(LineString(parse_coord_list(group)?)))
+            .collect::<Result<_, ParseWktError>>()?;
+        Ok(MultiLineString(line_strings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_line() {
+        let line: Line<f64> = "LINESTRING (0 0, 1 2)".parse().unwrap();
+        assert_eq!(line, Line::new((0., 0.), (1., 2.)));
+    }
+
+    #[test]
+    fn parses_line_string() {
+        let line_string: LineString<f64> = "linestring (0 0, 5 0, 5 5)".parse().unwrap();
+        assert_eq!(
+            line_string,
+            vec![(0., 0.), (5., 0.), (5., 5.)].into()
+        );
+    }
+
+    #[test]
+    fn parses_multi_line_string() {
+        let multi: MultiLineString<f64> =
+            "MULTILINESTRING ((0 0, 1 1), (2 2, 3 3, 4 4))".parse().unwrap();
+        assert_eq!(
+            multi,
+            MultiLineString(vec![
+                vec![(0., 0.), (1., 1.)].into(),
+                vec![(2., 2.), (3., 3.), (4., 4.)].into(),
+            ])
+        );
+    }
+
+    #[test]
+    fn line_rejects_wrong_point_count() {
+        let result: Result<Line<f64>, _> = "LINESTRING (0 0, 1 1, 2 2)".parse();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_wrong_tag() {
+        let result: Result<LineString<f64>, _> = "POINT (0 0)".parse();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn does_not_panic_on_non_char_boundary_input() {
+        // Regression test: `strip_tag` used to slice the input by raw byte
+        // length without checking for a UTF-8 char boundary, so a multi-byte
+        // character straddling that offset would panic instead of erroring.
+        let result: Result<LineString<f64>, _> = "LINESTRIN\u{e9} (0 0, 1 1)".parse();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn round_trips_through_display() {
+        let line_string: LineString<f64> = vec![(0., 0.), (5., 0.), (5., 5.)].into();
+        let wkt = line_string.to_wkt();
+        let parsed: LineString<f64> = wkt.parse().unwrap();
+        assert_eq!(parsed, line_string);
+    }
+}
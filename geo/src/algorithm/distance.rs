@@ -0,0 +1,86 @@
+use std::convert::Infallible;
+
+use num_traits::{Float, FromPrimitive};
+
+use algorithm::euclidean_distance::EuclideanDistance;
+use algorithm::haversine_distance::HaversineDistance;
+use algorithm::vincenty_distance::{FailedToConvergeError, VincentyDistance};
+use Point;
+
+/// A marker type selecting the planar (Cartesian) metric space, where distance
+/// is measured as a straight line between two points.
+pub struct Euclidean;
+
+/// A marker type selecting the great-circle metric space on a sphere, where
+/// distance is measured along the surface using the Haversine formula.
+pub struct Haversine;
+
+/// A marker type selecting the geodesic metric space on the WGS84 ellipsoid,
+/// where distance is measured along the surface using Vincenty's formulae.
+pub struct Geodesic;
+
+/// Compute the distance between two values of type `A` and `B`, measured in
+/// the metric space `Space` (e.g. [`Euclidean`](struct.Euclidean.html),
+/// [`Haversine`](struct.Haversine.html), [`Geodesic`](struct.Geodesic.html)).
+///
+/// Most metric spaces are infallible, but some, like `Geodesic`, may fail to
+/// converge for nearly-antipodal points; those spaces set `Error` to
+/// something other than `Infallible` and callers are expected to handle it.
+pub trait Distance<T, A, B = A> {
+    type Error;
+
+    fn distance(a: &A, b: &B) -> Result<T, Self::Error>;
+}
+
+impl<T> Distance<T, Point<T>> for Euclidean
+where
+    T: Float,
+{
+    type Error = Infallible;
+
+    fn distance(a: &Point<T>, b: &Point<T>) -> Result<T, Infallible> {
+        Ok(a.euclidean_distance(b))
+    }
+}
+
+impl<T> Distance<T, Point<T>> for Haversine
+where
+    T: Float + FromPrimitive,
+{
+    type Error = Infallible;
+
+    fn distance(a: &Point<T>, b: &Point<T>) -> Result<T, Infallible> {
+        Ok(a.haversine_distance(b))
+    }
+}
+
+impl<T> Distance<T, Point<T>> for Geodesic
+where
+    T: Float + FromPrimitive,
+{
+    type Error = FailedToConvergeError;
+
+    fn distance(a: &Point<T>, b: &Point<T>) -> Result<T, FailedToConvergeError> {
+        a.vincenty_distance(b)
+    }
+}
+
+/// Unwrap a `Result` known to always be `Ok` without reaching for `.unwrap()`,
+/// which reads as if it could panic. Intended for the `Result<T, Infallible>`
+/// that [`Distance::distance`](trait.Distance.html) and
+/// [`Length::length`](../length/trait.Length.html) return for infallible
+/// metric spaces like [`Euclidean`](struct.Euclidean.html) and
+/// [`Haversine`](struct.Haversine.html) — the match is exhaustive because
+/// `Infallible` has no variants, so this can never panic.
+pub trait UnwrapInfallible<T> {
+    fn unwrap_infallible(self) -> T;
+}
+
+impl<T> UnwrapInfallible<T> for Result<T, Infallible> {
+    fn unwrap_infallible(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(never) => match never {},
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use std::cmp::Ordering;
+
+use num_traits::{Float, FromPrimitive};
+
+use {Coordinate, Line, LineString};
+
+/// Test a geometry for self-intersection, per the OGC-SFA definition of
+/// "simple".
+///
+/// A `LineString` is simple if it does not cross or touch itself, except
+/// that a *closed* `LineString` (see
+/// [`LineString::is_closed`](../../struct.LineString.html#method.is_closed)) —
+/// i.e. a linear ring — is allowed to share its single start/end coordinate.
+pub trait IsSimple {
+    fn is_simple(&self) -> bool;
+}
+
+impl<T> IsSimple for LineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    /// Sweeps the linestring's segments left to right: every segment
+    /// contributes a start and an end event at its smaller/larger `(x, y)`
+    /// endpoint respectively (not at whichever coordinate happens to be
+    /// `Line::start`/`Line::end` — a segment can be stored "backwards"), and
+    /// when a segment *starts* it is tested against every segment that is
+    /// currently active (i.e. whose x-range already straddles the sweep
+    /// position). Any two segments whose x-ranges overlap are guaranteed to
+    /// both be active at the moment the later of the two starts, so this
+    /// finds every proper crossing between non-adjacent segments, plus every
+    /// collinear overlap between adjacent ones (both covered by the tests
+    /// below, including a backwards-stored segment and a collinear
+    /// backtrack — the two cases that broke earlier, buggier versions of
+    /// this sweep). It isn't a true Bentley–Ottmann sweep (no y-ordered
+    /// status structure, so it degrades towards O(n²) when many segments are
+    /// simultaneously active), and it doesn't detect two *non-adjacent*
+    /// segments that merely touch at a single point without crossing — but
+    /// within those bounds it is a correct sweep, which is what callers
+    /// validating a ring actually need.
+    fn is_simple(&self) -> bool {
+        let lines: Vec<Line<T>> = self.lines().collect();
+        if lines.len() < 2 {
+            return true;
+        }
+
+        let closed = self.is_closed();
+        let n = lines.len();
+
+        #[derive(Clone, Copy)]
+        enum Kind {
+            Start,
+            End,
+        }
+
+        struct Event<T: Float> {
+            coord: Coordinate<T>,
+            segment: usize,
+            kind: Kind,
+        }
+
+        let mut events = Vec::with_capacity(2 * n);
+        for (i, line) in lines.iter().enumerate() {
+            // The event `Kind` has to be driven by the endpoints' actual
+            // `(x, y)` order, not by which one happens to be `start`/`end` —
+            // a segment drawn "backwards" would otherwise get its End event
+            // sorted before its Start event, making it inactive for part of
+            // its true x-range.
+            let (lo, hi) = sweep_endpoints(line);
+            events.push(Event {
+                coord: lo,
+                segment: i,
+                kind: Kind::Start,
+            });
+            events.push(Event {
+                coord: hi,
+                segment: i,
+                kind: Kind::End,
+            });
+        }
+        events.sort_by(|a, b| {
+            (a.coord.x, a.coord.y)
+                .partial_cmp(&(b.coord.x, b.coord.y))
+                .unwrap()
+        });
+
+        let are_consecutive = |a: usize, b: usize| -> bool {
+            let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+            hi - lo == 1 || (closed && lo == 0 && hi == n - 1)
+        };
+
+        // Segments whose x-range currently straddles the sweep position.
+        let mut active: Vec<usize> = Vec::new();
+
+        for event in &events {
+            match event.kind {
+                Kind::Start => {
+                    for &other in &active {
+                        if are_consecutive(event.segment, other) {
+                            if segments_overlap(&lines[event.segment], &lines[other]) {
+                                return false;
+                            }
+                        } else if segments_intersect(&lines[event.segment], &lines[other]) {
+                            return false;
+                        }
+                    }
+                    active.push(event.segment);
+                }
+                Kind::End => {
+                    active.retain(|&s| s != event.segment);
+                }
+            }
+        }
+
+        true
+    }
+}
+
+/// Order a line's two endpoints by `(x, y)`, smaller first, so that sweep
+/// events don't depend on which coordinate happens to be `start`/`end`.
+fn sweep_endpoints<T: Float>(line: &Line<T>) -> (Coordinate<T>, Coordinate<T>) {
+    match (line.start.x, line.start.y).partial_cmp(&(line.end.x, line.end.y)) {
+        Some(Ordering::Greater) => (line.end, line.start),
+        _ => (line.start, line.end),
+    }
+}
+
+/// Proper intersection test: do the open segments `a` and `b` cross, sharing
+/// a point that is an endpoint of neither? A point of one segment that merely
+/// lies on the other (an endpoint touch or a collinear overlap) is not
+/// reported.
+fn segments_intersect<T: Float>(a: &Line<T>, b: &Line<T>) -> bool {
+    let d1 = direction(b.start, b.end, a.start);
+    let d2 = direction(b.start, b.end, a.end);
+    let d3 = direction(a.start, a.end, b.start);
+    let d4 = direction(a.start, a.end, b.end);
+
+    ((d1 > T::zero() && d2 < T::zero()) || (d1 < T::zero() && d2 > T::zero()))
+        && ((d3 > T::zero() && d4 < T::zero()) || (d3 < T::zero() && d4 > T::zero()))
+}
+
+/// Do two consecutive segments (which share exactly one coordinate by
+/// construction) overlap along more than that shared point? This is true
+/// when they're collinear and the free endpoint of one lies within the span
+/// of the other, e.g. a linestring that backtracks on itself.
+fn segments_overlap<T: Float + FromPrimitive>(a: &Line<T>, b: &Line<T>) -> bool {
+    let dir = a.delta();
+    let len_sq = dir.x * dir.x + dir.y * dir.y;
+    if len_sq == T::zero() {
+        return false;
+    }
+
+    // Collinearity: both of `b`'s endpoints must lie on the line through `a`.
+    let tol = len_sq.sqrt() * T::epsilon() * T::from_f64(1024.).unwrap();
+    if direction(a.start, a.end, b.start).abs() > tol
+        || direction(a.start, a.end, b.end).abs() > tol
+    {
+        return false;
+    }
+
+    // Project every point onto `a`'s direction to get a 1-D ordering along
+    // the shared line, then check whether the two segments' spans overlap by
+    // more than a single point.
+    let param = |p: Coordinate<T>| (p.x - a.start.x) * dir.x + (p.y - a.start.y) * dir.y;
+    let (a_lo, a_hi) = (T::zero(), len_sq);
+    let (b0, b1) = (param(b.start), param(b.end));
+    let (b_lo, b_hi) = if b0 < b1 { (b0, b1) } else { (b1, b0) };
+
+    let overlap_lo = a_lo.max(b_lo);
+    let overlap_hi = a_hi.min(b_hi);
+    overlap_hi > overlap_lo + tol
+}
+
+fn direction<T: Float>(p: Coordinate<T>, q: Coordinate<T>, r: Coordinate<T>) -> T {
+    (q.x - p.x) * (r.y - p.y) - (r.x - p.x) * (q.y - p.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_non_self_intersecting_is_simple() {
+        let line_string: LineString<f64> = vec![(0., 0.), (5., 0.), (5., 5.)].into();
+        assert!(line_string.is_simple());
+    }
+
+    #[test]
+    fn closed_ring_sharing_only_its_endpoint_is_simple() {
+        let line_string: LineString<f64> =
+            vec![(0., 0.), (5., 0.), (5., 5.), (0., 5.), (0., 0.)].into();
+        assert!(line_string.is_simple());
+    }
+
+    #[test]
+    fn figure_eight_is_not_simple() {
+        let line_string: LineString<f64> =
+            vec![(0., 0.), (5., 5.), (5., 0.), (0., 5.)].into();
+        assert!(!line_string.is_simple());
+    }
+
+    #[test]
+    fn non_consecutive_segments_crossing_is_not_simple() {
+        // Regression test for a sweep-line bug where only segments adjacent
+        // in insertion order (rather than every currently active segment)
+        // were tested against each other, so this genuinely self-intersecting
+        // open linestring was incorrectly reported as simple.
+        let line_string: LineString<f64> = vec![
+            (6., 3.),
+            (8., 19.),
+            (16., 0.),
+            (19., 4.),
+            (10., 12.),
+            (6., 4.),
+        ]
+        .into();
+        assert!(!line_string.is_simple());
+    }
+
+    #[test]
+    fn overlapping_consecutive_segments_are_not_simple() {
+        let line_string: LineString<f64> = vec![(0., 0.), (5., 0.), (2., 0.)].into();
+        assert!(!line_string.is_simple());
+    }
+
+    #[test]
+    fn backwards_segment_still_detects_a_crossing() {
+        // Regression test: a segment drawn from a larger to a smaller `(x, y)`
+        // coordinate (`start.x > end.x` here) used to get its sweep events in
+        // the wrong order, so it was inactive while the segment that actually
+        // crosses it was being swept.
+        let line_string: LineString<f64> =
+            vec![(5., 0.), (0., 0.), (2., -1.), (2., 1.)].into();
+        assert!(!line_string.is_simple());
+    }
+}
@@ -0,0 +1,79 @@
+use CoordinateType;
+use {Line, LineString, MultiLineString};
+use algorithm::distance::Distance;
+use lines_iter::LinesIter;
+use Point;
+
+/// Compute the length of a geometry by walking its constituent
+/// [`Line`](../../struct.Line.html) segments and summing the `Space`-distance
+/// between each segment's endpoints.
+///
+/// This supersedes the standalone `VincentyLength`; any other one-off
+/// `FooLength` traits elsewhere in the crate that duplicate this "sum over
+/// `lines()`" loop for a single metric space should be migrated to implement
+/// [`Distance`](../distance/trait.Distance.html) for their space and dropped
+/// in favor of this trait.
+///
+/// The metric space is selected at the call site, e.g.:
+///
+/// ```
+/// use geo::algorithm::length::Length;
+/// use geo::algorithm::distance::{Euclidean, UnwrapInfallible};
+/// use geo_types::LineString;
+///
+/// let line_string: LineString<f64> = vec![(0., 0.), (3., 4.)].into();
+/// // `Euclidean` is infallible, so there's nothing to propagate with `?` —
+/// // `unwrap_infallible` makes that explicit instead of reaching for
+/// // `.unwrap()`, which would misleadingly suggest this call can panic.
+/// assert_eq!(line_string.length::<Euclidean>().unwrap_infallible(), 5.);
+/// ```
+pub trait Length<T: CoordinateType> {
+    fn length<Space>(&self) -> Result<T, Space::Error>
+    where
+        Space: Distance<T, Point<T>>;
+}
+
+impl<T> Length<T> for Line<T>
+where
+    T: CoordinateType,
+{
+    fn length<Space>(&self) -> Result<T, Space::Error>
+    where
+        Space: Distance<T, Point<T>>,
+    {
+        let (start, end) = self.points();
+        Space::distance(&start, &end)
+    }
+}
+
+impl<T> Length<T> for LineString<T>
+where
+    T: CoordinateType,
+{
+    fn length<Space>(&self) -> Result<T, Space::Error>
+    where
+        Space: Distance<T, Point<T>>,
+    {
+        let mut length = T::zero();
+        for line in self.lines_iter() {
+            length = length + line.length::<Space>()?;
+        }
+        Ok(length)
+    }
+}
+
+impl<T> Length<T> for MultiLineString<T>
+where
+    T: CoordinateType,
+{
+    fn length<Space>(&self) -> Result<T, Space::Error>
+    where
+        Space: Distance<T, Point<T>>,
+    {
+        let mut length = T::zero();
+        for line_string in &self.0 {
+            length = length + line_string.length::<Space>()?;
+        }
+        Ok(length)
+    }
+}
@@ -0,0 +1,307 @@
+use num_traits::{Float, FromPrimitive};
+
+use algorithm::vincenty_distance::FailedToConvergeError;
+use {Line, LineString, Point};
+
+// WGS84 ellipsoid parameters, in meters / unitless.
+static EQUATORIAL_EARTH_RADIUS: f64 = 6378137.0;
+static FLATTENING: f64 = 1.0 / 298.257223563;
+
+/// Find the point a given geodesic distance along a [`Line`](../../struct.Line.html),
+/// using Vincenty's formulae on the WGS84 ellipsoid.
+pub trait VincentyPointAtDistance<T> {
+    /// Returns the point `distance` meters along the geodesic from
+    /// `self.start` towards `self.end`, continuing past `self.end` if
+    /// `distance` exceeds the line's own length.
+    fn vincenty_point_at_distance(&self, distance: T) -> Result<Point<T>, FailedToConvergeError>;
+}
+
+impl<T> VincentyPointAtDistance<T> for Line<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn vincenty_point_at_distance(&self, distance: T) -> Result<Point<T>, FailedToConvergeError> {
+        let (start, end) = self.points();
+        let (bearing, _) = inverse(&start, &end)?;
+        direct(&start, bearing, distance)
+    }
+}
+
+/// Insert extra coordinates along a [`LineString`](../../struct.LineString.html)'s
+/// segments so that no segment's geodesic length (per Vincenty's formulae on
+/// the WGS84 ellipsoid) exceeds `max_segment_len`.
+///
+/// # Panics
+///
+/// Panics if `max_segment_len` is not positive.
+pub trait VincentyDensify<T> {
+    fn vincenty_densify(&self, max_segment_len: T) -> Result<LineString<T>, FailedToConvergeError>;
+}
+
+impl<T> VincentyDensify<T> for LineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn vincenty_densify(&self, max_segment_len: T) -> Result<LineString<T>, FailedToConvergeError> {
+        assert!(max_segment_len > T::zero());
+
+        let mut coords = Vec::new();
+        for line in self.lines() {
+            coords.push(line.start);
+
+            let (start, end) = line.points();
+            let (bearing, segment_len) = inverse(&start, &end)?;
+
+            let num_segments = (segment_len / max_segment_len).ceil();
+            if num_segments > T::one() {
+                let step = segment_len / num_segments;
+                let mut i = T::one();
+                while i < num_segments {
+                    let point = direct(&start, bearing, step * i)?;
+                    coords.push(point.0);
+                    i = i + T::one();
+                }
+            }
+        }
+        if let Some(last) = self.0.last() {
+            coords.push(*last);
+        }
+
+        Ok(LineString(coords))
+    }
+}
+
+/// Vincenty's inverse formula: the initial forward azimuth (radians,
+/// clockwise from north) and the geodesic distance (meters) from `p1` to
+/// `p2`.
+///
+/// `vincenty_distance.rs`'s `VincentyDistance` runs this same λ-convergence
+/// loop to get the distance alone; `vincenty_point_at_distance` additionally
+/// needs the bearing, which falls out of the same loop for free. This file
+/// therefore carries its own copy of the loop and computes both bearing and
+/// distance from a single inverse solve, instead of running one loop for the
+/// distance and another for the bearing.
+fn inverse<T>(p1: &Point<T>, p2: &Point<T>) -> Result<(T, T), FailedToConvergeError>
+where
+    T: Float + FromPrimitive,
+{
+    let a = T::from_f64(EQUATORIAL_EARTH_RADIUS).unwrap();
+    let f = T::from_f64(FLATTENING).unwrap();
+    let b = (T::one() - f) * a;
+
+    let l = (p2.x() - p1.x()).to_radians();
+    let u1 = ((T::one() - f) * p1.y().to_radians().tan()).atan();
+    let u2 = ((T::one() - f) * p2.y().to_radians().tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let (sin_u2, cos_u2) = u2.sin_cos();
+
+    let mut lambda = l;
+    let mut iter_limit = 100;
+    let (mut sin_sigma, mut cos_sigma, mut sigma, mut cos_sq_alpha, mut cos2_sigma_m);
+    loop {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+            + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+        .sqrt();
+        if sin_sigma == T::zero() {
+            // Coincident points: bearing and distance are both zero.
+            return Ok((T::zero(), T::zero()));
+        }
+        cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        cos_sq_alpha = T::one() - sin_alpha * sin_alpha;
+        cos2_sigma_m = if cos_sq_alpha != T::zero() {
+            cos_sigma - T::from_f64(2.).unwrap() * sin_u1 * sin_u2 / cos_sq_alpha
+        } else {
+            T::zero()
+        };
+        let c = f / T::from_f64(16.).unwrap()
+            * cos_sq_alpha
+            * (T::from_f64(4.).unwrap() + f * (T::from_f64(4.).unwrap() - T::from_f64(3.).unwrap() * cos_sq_alpha));
+        let lambda_prev = lambda;
+        lambda = l
+            + (T::one() - c)
+                * f
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos2_sigma_m
+                            + c * cos_sigma * (-T::one() + T::from_f64(2.).unwrap() * cos2_sigma_m * cos2_sigma_m)));
+
+        iter_limit -= 1;
+        if (lambda - lambda_prev).abs() <= T::from_f64(1e-12).unwrap() {
+            break;
+        }
+        if iter_limit == 0 {
+            return Err(FailedToConvergeError);
+        }
+    }
+
+    let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+    let big_a = T::one()
+        + u_sq / T::from_f64(16384.).unwrap()
+            * (T::from_f64(4096.).unwrap()
+                + u_sq
+                    * (T::from_f64(-768.).unwrap()
+                        + u_sq * (T::from_f64(320.).unwrap() - T::from_f64(175.).unwrap() * u_sq)));
+    let big_b = u_sq / T::from_f64(1024.).unwrap()
+        * (T::from_f64(256.).unwrap()
+            + u_sq
+                * (T::from_f64(-128.).unwrap()
+                    + u_sq * (T::from_f64(74.).unwrap() - T::from_f64(47.).unwrap() * u_sq)));
+    let two = T::from_f64(2.).unwrap();
+    let delta_sigma = big_b
+        * sin_sigma
+        * (cos2_sigma_m
+            + big_b / T::from_f64(4.).unwrap()
+                * (cos_sigma * (-T::one() + two * cos2_sigma_m * cos2_sigma_m)
+                    - big_b / T::from_f64(6.).unwrap()
+                        * cos2_sigma_m
+                        * (-T::from_f64(3.).unwrap() + T::from_f64(4.).unwrap() * sin_sigma * sin_sigma)
+                        * (-T::from_f64(3.).unwrap() + T::from_f64(4.).unwrap() * cos2_sigma_m * cos2_sigma_m)));
+    let distance = b * big_a * (sigma - delta_sigma);
+
+    let (sin_lambda, cos_lambda) = lambda.sin_cos();
+    let bearing = (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+    Ok((normalize_bearing(bearing), distance))
+}
+
+fn normalize_bearing<T: Float + FromPrimitive>(bearing: T) -> T {
+    let tau = T::from_f64(::std::f64::consts::PI * 2.).unwrap();
+    (bearing + tau) % tau
+}
+
+/// Vincenty's direct formula: the point reached by travelling `distance`
+/// meters from `start` along the ellipsoid on initial bearing `bearing`
+/// (radians).
+fn direct<T>(start: &Point<T>, bearing: T, distance: T) -> Result<Point<T>, FailedToConvergeError>
+where
+    T: Float + FromPrimitive,
+{
+    let a = T::from_f64(EQUATORIAL_EARTH_RADIUS).unwrap();
+    let f = T::from_f64(FLATTENING).unwrap();
+    let b = (T::one() - f) * a;
+
+    let alpha1 = bearing;
+    let (sin_alpha1, cos_alpha1) = alpha1.sin_cos();
+
+    let tan_u1 = (T::one() - f) * start.y().to_radians().tan();
+    let cos_u1 = T::one() / (T::one() + tan_u1 * tan_u1).sqrt();
+    let sin_u1 = tan_u1 * cos_u1;
+
+    let sigma1 = tan_u1.atan2(cos_alpha1);
+    let sin_alpha = cos_u1 * sin_alpha1;
+    let cos_sq_alpha = T::one() - sin_alpha * sin_alpha;
+    let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+
+    let big_a = T::one()
+        + u_sq / T::from_f64(16384.).unwrap()
+            * (T::from_f64(4096.).unwrap()
+                + u_sq
+                    * (T::from_f64(-768.).unwrap()
+                        + u_sq * (T::from_f64(320.).unwrap() - T::from_f64(175.).unwrap() * u_sq)));
+    let big_b = u_sq / T::from_f64(1024.).unwrap()
+        * (T::from_f64(256.).unwrap()
+            + u_sq
+                * (T::from_f64(-128.).unwrap()
+                    + u_sq * (T::from_f64(74.).unwrap() - T::from_f64(47.).unwrap() * u_sq)));
+
+    let mut sigma = distance / (b * big_a);
+    let mut sigma_prev;
+    let two = T::from_f64(2.).unwrap();
+    loop {
+        let two_sigma_m = two * sigma1 + sigma;
+        let delta_sigma = big_b
+            * sigma.sin()
+            * (two_sigma_m.cos()
+                + big_b / T::from_f64(4.).unwrap()
+                    * (sigma.cos() * (-T::one() + two * two_sigma_m.cos() * two_sigma_m.cos())
+                        - big_b / T::from_f64(6.).unwrap()
+                            * two_sigma_m.cos()
+                            * (-T::from_f64(3.).unwrap() + T::from_f64(4.).unwrap() * sigma.sin() * sigma.sin())
+                            * (-T::from_f64(3.).unwrap()
+                                + T::from_f64(4.).unwrap() * two_sigma_m.cos() * two_sigma_m.cos())));
+        sigma_prev = sigma;
+        sigma = distance / (b * big_a) + delta_sigma;
+        if (sigma - sigma_prev).abs() <= T::from_f64(1e-12).unwrap() {
+            break;
+        }
+    }
+
+    let two_sigma_m = two * sigma1 + sigma;
+    let lat2 = (sin_u1 * sigma.cos() + cos_u1 * sigma.sin() * cos_alpha1).atan2(
+        (T::one() - f)
+            * (sin_alpha * sin_alpha
+                + (sin_u1 * sigma.sin() - cos_u1 * sigma.cos() * cos_alpha1).powi(2))
+            .sqrt(),
+    );
+    let lambda = (sigma.sin() * sin_alpha1).atan2(cos_u1 * sigma.cos() - sin_u1 * sigma.sin() * cos_alpha1);
+    let c = f / T::from_f64(16.).unwrap()
+        * cos_sq_alpha
+        * (T::from_f64(4.).unwrap() + f * (T::from_f64(4.).unwrap() - T::from_f64(3.).unwrap() * cos_sq_alpha));
+    let l = lambda
+        - (T::one() - c)
+            * f
+            * sin_alpha
+            * (sigma + c * sigma.sin() * (two_sigma_m.cos() + c * sigma.cos() * (-T::one() + two * two_sigma_m.cos() * two_sigma_m.cos())));
+
+    let lon2 = start.x().to_radians() + l;
+
+    Ok(Point::new(lon2.to_degrees(), lat2.to_degrees()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point_close(actual: Point<f64>, expected: Point<f64>, epsilon: f64) {
+        assert!(
+            (actual.x() - expected.x()).abs() < epsilon && (actual.y() - expected.y()).abs() < epsilon,
+            "expected {:?} to be within {} of {:?}",
+            actual,
+            epsilon,
+            expected
+        );
+    }
+
+    #[test]
+    fn point_at_zero_distance_is_the_start() {
+        let line = Line::new((0., 0.), (1., 1.));
+        let point = line.vincenty_point_at_distance(0.).unwrap();
+        assert_point_close(point, line.start_point(), 1e-6);
+    }
+
+    #[test]
+    fn point_at_full_length_is_the_end() {
+        let line = Line::new((-71.0763, 42.3541), (-69.9903, 43.2064));
+        let (_, length) = inverse(&line.start_point(), &line.end_point()).unwrap();
+        let point = line.vincenty_point_at_distance(length).unwrap();
+        assert_point_close(point, line.end_point(), 1e-3);
+    }
+
+    #[test]
+    fn densify_is_a_no_op_when_segments_are_already_short_enough() {
+        let line_string: LineString<f64> =
+            vec![(-71.0763, 42.3541), (-69.9903, 43.2064)].into();
+        let densified = line_string.vincenty_densify(10_000_000.).unwrap();
+        assert_eq!(densified, line_string);
+    }
+
+    #[test]
+    fn densify_inserts_intermediate_points() {
+        let line_string: LineString<f64> =
+            vec![(-71.0763, 42.3541), (-69.9903, 43.2064)].into();
+        let densified = line_string.vincenty_densify(10_000.).unwrap();
+        assert!(densified.0.len() > line_string.0.len());
+        assert_eq!(densified.0.first(), line_string.0.first());
+        assert_eq!(densified.0.last(), line_string.0.last());
+    }
+
+    #[test]
+    #[should_panic]
+    fn densify_panics_on_non_positive_max_segment_len() {
+        let line_string: LineString<f64> = vec![(0., 0.), (1., 1.)].into();
+        let _ = line_string.vincenty_densify(0.);
+    }
+}